@@ -0,0 +1,118 @@
+use crate::types::chess_move::Move;
+
+//Draw-detection bookkeeping for `Position`: board/piece state lives alongside this.
+#[derive(Clone)]
+pub struct Position {
+    zobrist: u64,
+    //Every zobrist key reached so far this game, plus - during search - every key
+    //reached along the current line. Pushed in `make_move`, popped in `unmake_move`,
+    //so a search that backs out of a line backs its share of history out with it too.
+    zobrist_history: Vec<u64>,
+    //Half-moves since the last capture or pawn push; reset to 0 on either, incremented
+    //otherwise. The searcher forces a draw once this reaches HALFMOVE_CLOCK_LIMIT.
+    halfmove_clock: usize,
+    //The value `halfmove_clock` held before each `make_move`, so `unmake_move` can
+    //restore it instead of leaving the clock's forward-only mutation in place - which
+    //would otherwise leak across sibling moves at the same search node.
+    halfmove_clock_history: Vec<usize>,
+}
+
+impl Position {
+    #[inline]
+    pub fn get_zobrist(&self) -> u64 {
+        self.zobrist
+    }
+
+    #[inline]
+    pub fn get_zobrist_history(&self) -> &Vec<u64> {
+        &self.zobrist_history
+    }
+
+    #[inline]
+    pub fn get_halfmove_clock(&self) -> usize {
+        self.halfmove_clock
+    }
+
+    pub fn make_move(&mut self, move_: Move) {
+        self.push_halfmove_clock(move_.get_is_capture() || move_.get_is_pawn_move());
+        self.zobrist_history.push(self.zobrist);
+    }
+
+    pub fn unmake_move(&mut self) {
+        self.zobrist_history.pop();
+        self.pop_halfmove_clock();
+    }
+
+    //Saves the current halfmove clock, then updates it for the move just made: reset to
+    //0 if it resets the clock (capture or pawn push), incremented otherwise.
+    #[inline]
+    fn push_halfmove_clock(&mut self, resets_clock: bool) {
+        self.halfmove_clock_history.push(self.halfmove_clock);
+        self.halfmove_clock = if resets_clock { 0 } else { self.halfmove_clock + 1 };
+    }
+
+    //Undoes `push_halfmove_clock`, restoring the value it saved.
+    #[inline]
+    fn pop_halfmove_clock(&mut self) {
+        self.halfmove_clock = self.halfmove_clock_history.pop().expect("unmake_move called without a matching make_move");
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn position_with_clock(halfmove_clock: usize) -> Position {
+        Position {
+            zobrist: 1,
+            zobrist_history: Vec::new(),
+            halfmove_clock,
+            halfmove_clock_history: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn unmake_move_restores_halfmove_clock_after_a_reset() {
+        let mut position = position_with_clock(12);
+        position.push_halfmove_clock(true);
+        assert_eq!(position.get_halfmove_clock(), 0);
+
+        position.pop_halfmove_clock();
+        assert_eq!(position.get_halfmove_clock(), 12);
+    }
+
+    #[test]
+    fn unmake_move_restores_halfmove_clock_after_an_increment() {
+        let mut position = position_with_clock(5);
+        position.push_halfmove_clock(false);
+        assert_eq!(position.get_halfmove_clock(), 6);
+
+        position.pop_halfmove_clock();
+        assert_eq!(position.get_halfmove_clock(), 5);
+    }
+
+    #[test]
+    fn sibling_moves_at_the_same_node_see_the_same_pre_move_clock() {
+        //Regression test: make_move/unmake_move for sibling A must not leak its effect
+        //on the clock into sibling B explored afterwards at the same node.
+        let mut position = position_with_clock(7);
+
+        position.push_halfmove_clock(true);
+        position.pop_halfmove_clock();
+        assert_eq!(position.get_halfmove_clock(), 7);
+
+        position.push_halfmove_clock(false);
+        position.pop_halfmove_clock();
+        assert_eq!(position.get_halfmove_clock(), 7);
+    }
+
+    #[test]
+    fn unmake_move_pops_zobrist_history() {
+        let mut position = position_with_clock(0);
+        position.make_move(Move::null());
+        assert_eq!(position.get_zobrist_history().len(), 1);
+
+        position.unmake_move();
+        assert!(position.get_zobrist_history().is_empty());
+    }
+}
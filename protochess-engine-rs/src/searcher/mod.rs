@@ -1,4 +1,9 @@
 use std::collections::HashMap;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+use std::thread;
+use crossbeam_channel;
 use crate::types::chess_move::Move;
 use crate::position::Position;
 use crate::move_generator::MoveGenerator;
@@ -10,8 +15,81 @@ use crate::transposition_table::{TranspositionTable, Entry, EntryFlag};
 
 //An entry in the transposition table
 
+//Sentinel score returned (and propagated) when a search is aborted because the
+//time budget ran out. It's far outside the [-99999, 99999] mate-score range so
+//it can never be confused with a real evaluation.
+const TIME_UP: isize = isize::MIN / 2;
+//How often (in nodes_searched) we poll the clock while searching
+const TIME_CHECK_INTERVAL: usize = 1024;
+//Maximum number of check extensions allowed along any single root-to-leaf path,
+//so a long checking sequence can't extend the search indefinitely
+const MAX_CHECK_EXTENSIONS: u8 = 16;
+//Minimum depth at which it's worth doing a reduced-depth search just to find a move
+//to seed ordering with, when the transposition table has nothing for this node
+const IID_MIN_DEPTH: u8 = 4;
+//Score returned for a draw by repetition or the fifty-move rule
+const DRAW_SCORE: isize = 0;
+//Halfmove clock value (half-moves since the last capture or pawn push) at which the
+//fifty-move rule forces a draw
+const HALFMOVE_CLOCK_LIMIT: usize = 100;
+//Size of `pv_table`/`pv_length`, and therefore the deepest ply they can record. `ply`
+//grows by one on every recursive call regardless of `depth` (check extensions can hold
+//`depth` steady while `ply` keeps climbing), so this is checked explicitly rather than
+//assumed to track `depth`.
+const MAX_PLY: usize = 64;
+//Number of independent TranspositionTable shards behind SharedTranspositionTable. Each
+//shard has its own mutex, so Lazy-SMP worker threads probing/inserting different zobrist
+//keys don't serialize on one global lock.
+const TT_SHARD_COUNT: usize = 16;
+//Number of distinct aspiration-window sizes handed out to Lazy-SMP worker threads, cycled
+//through by worker id: the first this-many workers get distinct sizes, later ones reuse them.
+const ASPIRATION_WINDOW_VARIANT_COUNT: u64 = 4;
+//Base half-width (in centipawns) of a Lazy-SMP worker's aspiration window around its
+//previous iteration's score; scaled up per worker by its window variant.
+const ASPIRATION_WINDOW_BASE: isize = 25;
+
+//The UCI-style `go` parameters used to compute a time budget for a search
+#[derive(Clone, Copy, Debug, Default)]
+pub struct GoParams {
+    pub movetime: Option<u64>,
+    pub wtime: Option<u64>,
+    pub btime: Option<u64>,
+    pub winc: Option<u64>,
+    pub binc: Option<u64>,
+    pub movestogo: Option<u32>,
+}
+
+//Thread-safe handle to a sharded `TranspositionTable`, used by Lazy-SMP so every worker
+//thread reads and writes the same logical table instead of each keeping its own. The
+//table is split into TT_SHARD_COUNT independently-locked shards, keyed by zobrist, so
+//workers probing/inserting different positions aren't serialized behind one global
+//mutex. Cloning this handle is cheap (it's just an `Arc` bump); every worker gets its
+//own handle while sharing the underlying shards.
+#[derive(Clone)]
+struct SharedTranspositionTable(Arc<Vec<Mutex<TranspositionTable>>>);
+
+impl SharedTranspositionTable {
+    fn new() -> SharedTranspositionTable {
+        let shards = (0..TT_SHARD_COUNT).map(|_| Mutex::new(TranspositionTable::new())).collect();
+        SharedTranspositionTable(Arc::new(shards))
+    }
+
+    #[inline]
+    fn shard_for(&self, key: u64) -> &Mutex<TranspositionTable> {
+        &self.0[(key % TT_SHARD_COUNT as u64) as usize]
+    }
+
+    fn retrieve(&self, key: u64) -> Option<Entry> {
+        self.shard_for(key).lock().unwrap().retrieve(key)
+    }
+
+    fn insert(&self, key: u64, entry: Entry) {
+        self.shard_for(key).lock().unwrap().insert(key, entry);
+    }
+}
+
 pub(crate) struct Searcher {
-    transposition_table: TranspositionTable,
+    transposition_table: SharedTranspositionTable,
     //We store two killer moves per ply,
     //indexed by killer_moves[depth][0] or killer_moves[depth][0]
     killer_moves: [[Move;2];64],
@@ -22,19 +100,43 @@ pub(crate) struct Searcher {
     //Counter for the number of nodes searched
     nodes_searched: usize,
     nodes_fail_high_first:usize,
-    nodes_fail_high: usize
+    nodes_fail_high: usize,
+
+    //Time control: set when searching with a budget, None for a fixed-depth search
+    stop_time: Option<Instant>,
+    //Set once the time budget has been exceeded (or an external stop was requested);
+    //checked every TIME_CHECK_INTERVAL nodes so it aborts the in-progress search.
+    //An `Arc` so Lazy-SMP worker threads can share one flag and stop in lockstep.
+    stopped: Arc<AtomicBool>,
+
+    //Triangular PV table: pv_table[ply][ply..pv_length[ply]] is the best line found from `ply`
+    pv_table: [[Move; MAX_PLY]; MAX_PLY],
+    //pv_length[ply] is the index one past the last PV move in pv_table[ply], so the line from
+    //`ply` holds pv_length[ply] - ply moves (pv_length[ply] == ply means no PV)
+    pv_length: [usize; MAX_PLY]
 }
 
 impl Searcher {
     pub fn new() -> Searcher {
         let hasher = ahash::RandomState::new();
+        Searcher::new_worker(SharedTranspositionTable::new(), Arc::new(AtomicBool::new(false)))
+    }
+
+    //Builds a searcher that shares `transposition_table` and `stopped` with other workers,
+    //but keeps its own killer/history heuristics and PV table. Used by Lazy-SMP so each
+    //worker thread orders moves based on what it has itself discovered.
+    fn new_worker(transposition_table: SharedTranspositionTable, stopped: Arc<AtomicBool>) -> Searcher {
         Searcher{
-            transposition_table: TranspositionTable::new(),
+            transposition_table,
             killer_moves: [[Move::null(); 2];64],
             history_moves: [[0;256];256],
             nodes_searched: 0,
             nodes_fail_high: 0,
-            nodes_fail_high_first: 0
+            nodes_fail_high_first: 0,
+            stop_time: None,
+            stopped,
+            pv_table: [[Move::null(); MAX_PLY]; MAX_PLY],
+            pv_length: [0; MAX_PLY]
         }
     }
 
@@ -42,24 +144,257 @@ impl Searcher {
     pub fn get_best_move(&mut self, position: &mut Position, eval: &mut Evaluator, movegen: &MoveGenerator, depth: u8) -> Option<Move> {
         //Iterative deepening
         self.clear_heuristics();
+        //A previous timed search on this instance may have latched `stopped` when its
+        //budget ran out; clear it so this fixed-depth search actually runs instead of
+        //every node immediately returning TIME_UP.
+        self.stopped.store(false, Ordering::Relaxed);
         for d in 1..=depth {
-            let best_score = self.alphabeta(position, eval, movegen, d, -isize::MAX, isize::MAX, true);
+            let best_score = self.alphabeta(position, eval, movegen, d, -isize::MAX, isize::MAX, true, 0, 0);
             //Print PV info
             let ordering_percentage:f64 = if self.nodes_fail_high != 0 { (self.nodes_fail_high_first as f64) / (self.nodes_fail_high as f64) } else { 0.0 };
             println!("score:{} depth: {}, nodes: {}, ordering: {}", best_score, d, self.nodes_searched, ordering_percentage);
+            println!("pv {}", self.format_pv());
+
+            self.clear_search_stats();
+        }
+
+        match self.transposition_table.retrieve(position.get_zobrist()){
+            Some(entry) => {Some((&entry.move_).to_owned())}
+            None => None
+        }
+    }
+
+    //Iterative deepening up to `max_depth`, stopping early once the time budget computed from
+    //`go` runs out. Returns the best move found by the last fully-completed iteration.
+    pub fn get_best_move_timed(&mut self, position: &mut Position, eval: &mut Evaluator, movegen: &MoveGenerator,
+                                max_depth: u8, go: GoParams, is_white: bool) -> Option<Move> {
+        self.clear_heuristics();
+        self.stopped.store(false, Ordering::Relaxed);
+
+        let allocation = Searcher::allocate_time(&go, is_white);
+        let start = Instant::now();
+        self.stop_time = Some(start + allocation);
+
+        for d in 1..=max_depth {
+            //A fresh ply rarely finishes if we're already past ~60% of the budget
+            if start.elapsed() > allocation.mul_f64(0.6) {
+                break;
+            }
+
+            let best_score = self.alphabeta(position, eval, movegen, d, -isize::MAX, isize::MAX, true, 0, 0);
+
+            if self.stopped.load(Ordering::Relaxed) {
+                //This iteration was abandoned partway through; the TT still holds the
+                //last fully completed iteration's best move, so just stop here.
+                break;
+            }
+
+            let ordering_percentage:f64 = if self.nodes_fail_high != 0 { (self.nodes_fail_high_first as f64) / (self.nodes_fail_high as f64) } else { 0.0 };
+            println!("score:{} depth: {}, nodes: {}, ordering: {}", best_score, d, self.nodes_searched, ordering_percentage);
+            println!("pv {}", self.format_pv());
 
             self.clear_search_stats();
         }
 
+        self.stop_time = None;
+
         match self.transposition_table.retrieve(position.get_zobrist()){
             Some(entry) => {Some((&entry.move_).to_owned())}
             None => None
         }
     }
 
+    //Lazy-SMP: runs `num_threads` independent searchers in parallel, each on its own clone
+    //of `position` with its own killer/history tables, all sharing one sharded transposition
+    //table. Threads start at staggered depths spread across the full 1..=max_depth range (not
+    //just the first few plies) and, from their second iteration on, search an aspiration window
+    //around their own previous score whose half-width is one of ASPIRATION_WINDOW_VARIANT_COUNT
+    //sizes picked by worker id - so workers differ in start depth and/or window width instead
+    //of duplicating an earlier worker's search almost exactly. Every worker stops once worker 0
+    //(the main worker, itself a spawned thread; the calling thread only waits) reaches
+    //`max_depth`, OR - when `go` is given - once the single time budget computed from `go`,
+    //shared by all workers, runs out, same as `get_best_move_timed`; pass `None` for a purely
+    //depth-bounded search. The move is read back from the shared TT.
+    pub fn get_best_move_lazy_smp(position: &Position, eval: &Evaluator, movegen: &MoveGenerator,
+                                   max_depth: u8, num_threads: usize, go: Option<GoParams>, is_white: bool) -> Option<Move> {
+        let transposition_table = SharedTranspositionTable::new();
+        let stopped = Arc::new(AtomicBool::new(false));
+        let (done_tx, done_rx) = crossbeam_channel::bounded::<()>(num_threads);
+        let stop_time = go.map(|go| Instant::now() + Searcher::allocate_time(&go, is_white));
+
+        for worker_id in 0..num_threads {
+            let mut worker = Searcher::new_worker(transposition_table.clone(), stopped.clone());
+            worker.stop_time = stop_time;
+            let mut worker_position = position.clone();
+            let mut worker_eval = eval.clone();
+            let worker_movegen = movegen.clone();
+            let done_tx = done_tx.clone();
+            let is_main = worker_id == 0;
+            //Helper threads start at a depth spread across the full depth range (instead of
+            //just the first three plies), so threads beyond the third stop retracing one of
+            //the first three workers' searches almost exactly
+            let start_depth = 1 + (worker_id as u8 % max_depth.max(1));
+            //Each worker gets one of a handful of aspiration-window half-widths, so
+            //concurrently-running workers bias their search toward different parts of the
+            //tree even when they share a starting depth
+            let window = ASPIRATION_WINDOW_BASE * (1 + (worker_id as u64 % ASPIRATION_WINDOW_VARIANT_COUNT)) as isize;
+
+            thread::spawn(move || {
+                let mut prev_score: Option<isize> = None;
+                for d in start_depth..=max_depth {
+                    if worker.is_stopped() {
+                        break;
+                    }
+                    let (alpha, beta) = match prev_score {
+                        //No result from this worker yet to center a window on; search full width
+                        None => (-isize::MAX, isize::MAX),
+                        Some(score) => (score - window, score + window)
+                    };
+                    let mut score = worker.alphabeta(&mut worker_position, &mut worker_eval, &worker_movegen,
+                                                      d, alpha, beta, true, 0, 0);
+                    if !worker.is_stopped() && (score <= alpha || score >= beta) {
+                        //Aspiration window missed; re-search this depth with the full window
+                        //before trusting the score
+                        score = worker.alphabeta(&mut worker_position, &mut worker_eval, &worker_movegen,
+                                                  d, -isize::MAX, isize::MAX, true, 0, 0);
+                    }
+                    prev_score = Some(score);
+                    worker.clear_search_stats();
+                }
+                if is_main {
+                    //The main thread reaching max_depth (or running out of time) is what
+                    //defines "done" for this search; tell every other worker to stop too
+                    worker.stopped.store(true, Ordering::Relaxed);
+                }
+                let _ = done_tx.send(());
+            });
+        }
+        drop(done_tx);
+
+        for _ in 0..num_threads {
+            let _ = done_rx.recv();
+        }
+
+        transposition_table.retrieve(position.get_zobrist()).map(|entry| entry.move_)
+    }
+
+    //Computes how long the upcoming search is allowed to run for, given UCI-style `go` params
+    fn allocate_time(go: &GoParams, is_white: bool) -> Duration {
+        if let Some(movetime) = go.movetime {
+            return Duration::from_millis(movetime);
+        }
+
+        let (time, inc) = if is_white {
+            (go.wtime, go.winc.unwrap_or(0))
+        } else {
+            (go.btime, go.binc.unwrap_or(0))
+        };
+
+        match time {
+            Some(remaining) => {
+                let movestogo = go.movestogo.unwrap_or(30).max(1) as u64;
+                let millis = remaining / movestogo + inc;
+                Duration::from_millis(millis)
+            }
+            //No time control given at all; fall back to a conservative fixed budget
+            None => Duration::from_secs(5)
+        }
+    }
+
+    //Checked every TIME_CHECK_INTERVAL nodes; sets `stopped` once the budget is exhausted
+    #[inline]
+    fn check_time(&mut self) {
+        if self.nodes_searched % TIME_CHECK_INTERVAL != 0 {
+            return;
+        }
+        if let Some(stop_time) = self.stop_time {
+            if Instant::now() >= stop_time {
+                self.stopped.store(true, Ordering::Relaxed);
+            }
+        }
+    }
+
+    #[inline]
+    fn is_stopped(&self) -> bool {
+        self.stopped.load(Ordering::Relaxed)
+    }
+
+    //Renders the principal variation found at the root (ply 0) as e.g. "e2e4 e7e5 g1f3"
+    fn format_pv(&self) -> String {
+        self.pv_table[0][0..self.pv_length[0]]
+            .iter()
+            .map(|m| m.to_string())
+            .collect::<Vec<_>>()
+            .join(" ")
+    }
+
+    //True if `position` should be scored as a draw: its zobrist key has already appeared
+    //earlier in the game (or earlier along the current search path - `make_move`/`unmake_move`
+    //push and pop the same history stack during search), or the fifty-move clock ran out.
+    #[inline]
+    fn is_draw(position: &Position) -> bool {
+        if position.get_halfmove_clock() >= HALFMOVE_CLOCK_LIMIT {
+            return true;
+        }
+        let current = position.get_zobrist();
+        position.get_zobrist_history().iter().rev().skip(1).any(|&key| key == current)
+    }
+
+    //True if `score` is within mate-distance of the -99999/99999 bound; pruning must
+    //never trigger near these values or it could miss (or miss avoiding) a mate.
+    #[inline]
+    fn is_mate_score(score: isize) -> bool {
+        score.abs() >= 90000
+    }
+
+    //True if `position` has at least one legal move. Used to keep pruning that fires
+    //before the move loop (razoring) from mistaking a genuine stalemate for a position
+    //merely worth a quiescence value.
+    #[inline]
+    fn has_legal_move(movegen: &MoveGenerator, position: &mut Position) -> bool {
+        movegen.get_pseudo_moves(position).any(|mv| movegen.is_move_legal(&mv, position))
+    }
+
+    #[inline]
+    fn razor_margin(depth: u8) -> isize {
+        match depth {
+            1 => 300,
+            2 => 500,
+            _ => 900
+        }
+    }
+
+    #[inline]
+    fn futility_margin(depth: u8) -> isize {
+        match depth {
+            1 => 300,
+            _ => 500
+        }
+    }
+
     fn alphabeta(&mut self, position: &mut Position, eval: &mut Evaluator, movegen: &MoveGenerator,
-                     depth: u8, mut alpha: isize, mut beta: isize, do_null: bool) -> isize {
+                     depth: u8, mut alpha: isize, mut beta: isize, do_null: bool, extensions: u8, ply: usize) -> isize {
         self.nodes_searched += 1;
+        self.check_time();
+        if self.is_stopped() {
+            return TIME_UP;
+        }
+
+        //`pv_table`/`pv_length` only have room for `MAX_PLY` plies; check extensions can
+        //drive `ply` past where `depth` alone would have stopped, so settle the position
+        //with quiescence instead of indexing either array out of bounds.
+        if ply >= MAX_PLY {
+            return self.quiesce(position, eval, movegen, 0, alpha, beta);
+        }
+
+        self.pv_length[ply] = ply;
+
+        //Draws: a position repeated earlier in the game (or already reached along this
+        //search path, since make_move/unmake_move maintain the same zobrist stack) or a
+        //stale fifty-move clock is worth exactly DRAW_SCORE, regardless of material.
+        if ply > 0 && Searcher::is_draw(position) {
+            return DRAW_SCORE;
+        }
 
         if depth == 0 {
             return self.quiesce(position, eval, movegen, depth, alpha, beta);
@@ -92,10 +427,47 @@ impl Searcher {
             }
         }
         //Null move pruning
-        if let Some(beta) = self.try_null_move(position, eval, movegen, depth, alpha, beta, do_null){
+        if let Some(beta) = self.try_null_move(position, eval, movegen, depth, alpha, beta, do_null, extensions, ply){
             return beta;
         }
 
+        let in_check = movegen.in_check(position);
+        let near_mate_bound = Searcher::is_mate_score(alpha) || Searcher::is_mate_score(beta);
+
+        //Razoring: if we're so far below alpha that only tactics (captures) could save us,
+        //skip straight to quiescence instead of searching the full subtree. Guarded by a
+        //legal-move check so a genuine stalemate (no legal moves, not in check) still falls
+        //through to the move loop below and is scored as a draw instead of a quiesce value.
+        if !in_check && !near_mate_bound && depth <= 3 {
+            let razor_margin = Searcher::razor_margin(depth);
+            let static_eval = eval.evaluate(position, movegen);
+            if static_eval + razor_margin < alpha && Searcher::has_legal_move(movegen, position) {
+                return self.quiesce(position, eval, movegen, 0, alpha, beta);
+            }
+        }
+
+        //Futility pruning margin: computed once per node, used to skip hopeless quiet
+        //moves in the move loop below.
+        let futility_threshold = if !in_check && !near_mate_bound && depth >= 1 && depth <= 2 {
+            Some(eval.evaluate(position, movegen) + Searcher::futility_margin(depth))
+        } else {
+            None
+        };
+
+        //Internal iterative deepening: at high enough depth, if we have no hash move to
+        //seed move ordering with, do a cheap reduced-depth search purely to populate the
+        //transposition table, then re-retrieve it below.
+        if depth >= IID_MIN_DEPTH && self.transposition_table.retrieve(position.get_zobrist()).is_none() {
+            self.alphabeta(position, eval, movegen, depth - 2, alpha, beta, false, extensions, ply);
+            //The probe above shares this node's `ply`, so its own prologue just overwrote
+            //pv_length[ply] with whatever shallow, reduced-depth line it found. Reset it
+            //back to empty so that line can't survive into the real search below: either a
+            //move in the move loop that follows raises alpha and writes the real line, or
+            //none does and the parent correctly sees no PV from this node, instead of the
+            //probe's throwaway one.
+            self.pv_length[ply] = ply;
+        }
+
         let mut moves_and_score = self.get_scored_pseudo_moves(eval, movegen, position, depth);
         let mut best_move = Move::null();
         let mut num_legal_moves = 0;
@@ -111,23 +483,57 @@ impl Searcher {
                 continue;
             }
 
+            //Count this move toward num_legal_moves before futility pruning can skip it:
+            //it's still a legal move, so a node where pruning removes every quiet move is
+            //not a stalemate and must not fall into the num_legal_moves == 0 branch below.
             num_legal_moves += 1;
+
+            //Futility pruning: a quiet, non-promoting move that can't possibly raise the
+            //static eval above alpha is assumed not worth searching, unless it gives check.
+            if let Some(threshold) = futility_threshold {
+                if threshold < alpha && !move_.get_is_capture() && !move_.get_is_promotion() {
+                    position.make_move((&move_).to_owned());
+                    let gives_check = movegen.in_check(position);
+                    position.unmake_move();
+                    if !gives_check {
+                        continue;
+                    }
+                }
+            }
+
             position.make_move((&move_).to_owned());
+
+            //Check extensions: a move that gives check is searched one ply deeper so
+            //forcing sequences aren't cut off at the horizon. Capped per line so a long
+            //checking sequence can't extend the search indefinitely.
+            let (next_depth, next_extensions) = if extensions < MAX_CHECK_EXTENSIONS && ply + 1 < MAX_PLY
+                && movegen.in_check(position) {
+                (depth, extensions + 1)
+            } else {
+                (depth - 1, extensions)
+            };
+
             let mut score = 0;
             if search_pv {
                 score = -self.alphabeta(position, eval, movegen,
-                                        depth - 1, -beta, -alpha, true);
+                                        next_depth, -beta, -alpha, true, next_extensions, ply + 1);
             }else{
                 score = -self.alphabeta(position, eval, movegen,
-                                        depth - 1, -alpha - 1, -alpha, true);
+                                        next_depth, -alpha - 1, -alpha, true, next_extensions, ply + 1);
                 if score > alpha  && score < beta {
                     score = -self.alphabeta(position, eval, movegen,
-                                            depth - 1, -beta, -alpha, true);
+                                            next_depth, -beta, -alpha, true, next_extensions, ply + 1);
                 }
             }
 
             position.unmake_move();
 
+            if self.is_stopped() {
+                //Time ran out somewhere below us; unwind without recording this node,
+                //so the last fully-completed iteration's TT entry is left untouched.
+                return TIME_UP;
+            }
+
             if score > best_score {
                 best_score = score;
                 best_move = move_;
@@ -153,6 +559,21 @@ impl Searcher {
                     search_pv = false;
                     alpha = score;
 
+                    //Record this move into the triangular PV table: it becomes ply's move,
+                    //followed by whatever line ply+1 already found. ply+1 can be MAX_PLY
+                    //(the recursive call bottomed out into quiesce without touching the PV
+                    //arrays at all), in which case there's nothing to copy from beyond this
+                    //move itself.
+                    self.pv_table[ply][ply] = (&move_).to_owned();
+                    if ply + 1 < MAX_PLY {
+                        for next_ply in (ply + 1)..self.pv_length[ply + 1] {
+                            self.pv_table[ply][next_ply] = self.pv_table[ply + 1][next_ply];
+                        }
+                        self.pv_length[ply] = self.pv_length[ply + 1];
+                    } else {
+                        self.pv_length[ply] = ply + 1;
+                    }
+
                     //History heuristic
                     self.update_history_heuristic(depth, &move_);
                 }
@@ -194,6 +615,10 @@ impl Searcher {
     fn quiesce(&mut self, position: &mut Position, eval: &mut Evaluator, movegen: &MoveGenerator,
                  depth:u8, mut alpha: isize, mut beta: isize) -> isize {
         self.nodes_searched += 1;
+        self.check_time();
+        if self.is_stopped() {
+            return TIME_UP;
+        }
         let mut score = eval.evaluate(position, movegen);
         if score >= beta{
             return beta;
@@ -221,6 +646,10 @@ impl Searcher {
                                          depth, -beta, -alpha);
             position.unmake_move();
 
+            if self.is_stopped() {
+                return TIME_UP;
+            }
+
             if score >= beta {
                 if num_legal_moves == 1 {
                     self.nodes_fail_high_first += 1;
@@ -266,6 +695,7 @@ impl Searcher {
                     self.history_moves[i][j] = 0;
             }
         }
+        self.pv_length = [0; MAX_PLY];
     }
 
     fn clear_search_stats(&mut self) {
@@ -336,13 +766,13 @@ impl Searcher {
 
     #[inline]
     fn try_null_move(&mut self, position: &mut Position, eval: &mut Evaluator, movegen: &MoveGenerator,
-                 depth: u8, mut alpha: isize, mut beta: isize, do_null: bool) -> Option<isize> {
+                 depth: u8, mut alpha: isize, mut beta: isize, do_null: bool, extensions: u8, ply: usize) -> Option<isize> {
         if do_null {
             if depth > 3 && eval.can_do_null_move(position)
                 && !movegen.in_check(position) {
                 position.make_move(Move::null());
                 let nscore = -self.alphabeta(position,eval, movegen,
-                                             depth - 3, -beta, -beta + 1, false);
+                                             depth - 3, -beta, -beta + 1, false, extensions, ply + 1);
                 position.unmake_move();
                 if nscore >= beta {
                     return Some(beta);